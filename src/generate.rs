@@ -0,0 +1,124 @@
+//! 随机生成语法合法的二代身份证号 (18位)，主要用于测试与造数。
+
+use core::ops::RangeInclusive;
+use core::str;
+
+use crate::{check_digit, validate_ymd, Sex, DATASET};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// [`generate`] 的约束项；未设置的字段在合法范围内均匀随机取值。
+#[derive(Debug, Clone, Default)]
+pub struct FakeOptions {
+    /// 固定的 6 位行政区划代码；为 `None` 时从数据集中按年份随机选取。
+    pub region_code: Option<[u8; 6]>,
+    /// 出生年份的闭区间；为 `None` 时取 `1950..=2020`。
+    pub year_range: Option<RangeInclusive<u16>>,
+    /// 固定出生月份 (1..=12)。
+    pub month: Option<u8>,
+    /// 固定出生日 (1..=31)。
+    pub day: Option<u8>,
+    /// 固定性别；顺序码末位的奇偶性据此决定。
+    pub sex: Option<Sex>,
+}
+
+/// 按 `opts` 生成一个可通过 [`parse_v2`](crate::parse_v2) 的 18 位号码。
+///
+/// 固定字段不做校验：月日均被固定且组合非法、或年份不在 `1801..=2199` 内时，
+/// 结果按原样写入，无法通过 [`parse_v2`](crate::parse_v2)。
+#[must_use]
+pub fn generate(opts: &FakeOptions) -> String {
+    let mut rng = rand::thread_rng();
+
+    let year = match &opts.year_range {
+        Some(r) => rng.gen_range(r.clone()),
+        None => rng.gen_range(1950..=2020),
+    };
+
+    let region_code = opts.region_code.unwrap_or_else(|| random_region_code(&mut rng, year));
+
+    let (month, day) = loop {
+        let m = opts.month.unwrap_or_else(|| rng.gen_range(1..=12));
+        let d = opts.day.unwrap_or_else(|| rng.gen_range(1..=31));
+        // 月日均被固定时直接采用，避免非法组合导致死循环。
+        if validate_ymd(year, m, d).is_some() || (opts.month.is_some() && opts.day.is_some()) {
+            break (m, d);
+        }
+    };
+
+    let want_odd = matches!(
+        opts.sex.unwrap_or(if rng.gen() { Sex::Male } else { Sex::Female }),
+        Sex::Male
+    );
+    let seq = rng.gen_range(0u16..=99) * 10 + rng.gen_range(0u16..=4) * 2 + u16::from(want_odd);
+
+    let mut body = [0u8; 17];
+    body[..6].copy_from_slice(&region_code);
+    write_u16(&mut body[6..10], year, 4);
+    write_u16(&mut body[10..12], u16::from(month), 2);
+    write_u16(&mut body[12..14], u16::from(day), 2);
+    write_u16(&mut body[14..17], seq, 3);
+
+    let mut id = [0u8; 18];
+    id[..17].copy_from_slice(&body);
+    id[17] = check_digit(&body);
+    str::from_utf8(&id).unwrap().to_owned()
+}
+
+/// 从数据集中为 `year` 选取一个 6 位代码，优先使用区县级（非 `..00`）代码。
+fn random_region_code(rng: &mut impl Rng, year: u16) -> [u8; 6] {
+    let map = DATASET
+        .get(&year)
+        .or_else(|| {
+            DATASET
+                .iter()
+                .filter(|(y, _)| **y <= year)
+                .max_by_key(|(y, _)| **y)
+                .map(|(_, m)| m)
+        })
+        .or_else(|| DATASET.values().next());
+
+    let fallback = [b'1', b'1', b'0', b'1', b'0', b'1'];
+    let Some(map) = map else { return fallback };
+
+    let districts: Vec<&&str> = map
+        .keys()
+        .filter(|k| k.len() == 6 && k.bytes().all(|b| b.is_ascii_digit()) && !k.ends_with("00"))
+        .collect();
+
+    match districts.choose(rng) {
+        Some(code) => code.as_bytes().try_into().unwrap(),
+        None => fallback,
+    }
+}
+
+/// 将 `value` 以固定 `width` 位、前导零的形式写入 `dst`。
+fn write_u16(dst: &mut [u8], mut value: u16, width: usize) {
+    for i in (0..width).rev() {
+        dst[i] = b'0' + u8::try_from(value % 10).unwrap();
+        value /= 10;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_v2;
+
+    #[test]
+    fn round_trip() {
+        let opts = FakeOptions {
+            region_code: Some(*b"420111"),
+            year_range: Some(1982..=1982),
+            month: Some(3),
+            day: Some(25),
+            sex: Some(Sex::Female),
+        };
+        let id = generate(&opts);
+        let parsed = parse_v2(&id).unwrap();
+        assert_eq!(parsed.birthday_ymd(), (1982, 3, 25));
+        assert_eq!(parsed.sex(), Sex::Female);
+        assert_eq!(parsed.region().district, Some("洪山区"));
+    }
+}
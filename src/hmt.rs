@@ -0,0 +1,163 @@
+//! 港澳台身份证件号码的校验与解析。
+//!
+//! 三地各自使用不同的校验算法，与内地 GB 11643-1999 互不影响，
+//! 统一复用 crate 根部的 [`Error`](crate::Error) 枚举报告失败。
+
+use crate::Error;
+
+/// 证件签发地。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Issuer {
+    /// 香港永久性居民身份证 (HKID)
+    HongKong,
+    /// 澳门居民身份证
+    Macau,
+    /// 台湾身份证
+    Taiwan,
+}
+
+/// 港澳台证件解析结果，按签发地标记。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionalIdNumber {
+    /// 签发地
+    pub issuer: Issuer,
+    /// 归一化后的号码（去除首尾空白，保留原有大小写）
+    pub serial: String,
+}
+
+/// 香港身份证 (HKID)：一到两位大写字母 + 6 位数字 + 括号内校验位。
+///
+/// 单字母卡在最高位左补一个空格（取值 36），字母 `A`–`Z` 取值 10–35，
+/// 各位权重由高到低为 9…2，校验值为 `11 - (加权和 mod 11)`，结果 10 记作 `'A'`、
+/// 11 记作 `'0'`。
+pub fn parse_hkid(id: &str) -> Result<RegionalIdNumber, Error> {
+    let trimmed = id.trim();
+
+    let (body, check) = match trimmed
+        .strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once('('))
+    {
+        Some((body, check)) if check.len() == 1 => (body.as_bytes(), check.as_bytes()[0]),
+        _ => return Err(Error::InvalidCharacter),
+    };
+
+    let split = body.len().checked_sub(6).ok_or(Error::InvalidLength)?;
+    let (letters, digits) = body.split_at(split);
+    if letters.is_empty() || letters.len() > 2 {
+        return Err(Error::InvalidLength);
+    }
+    if !letters.iter().all(u8::is_ascii_uppercase) || !digits.iter().all(u8::is_ascii_digit) {
+        return Err(Error::InvalidCharacter);
+    }
+
+    // 8 个加权位，缺失的首字母位以空格 (36) 左补。
+    let mut values = [36u32; 8];
+    let offset = 2 - letters.len();
+    for (i, &b) in letters.iter().enumerate() {
+        values[offset + i] = u32::from(b - b'A') + 10;
+    }
+    for (i, &b) in digits.iter().enumerate() {
+        values[2 + i] = u32::from(b - b'0');
+    }
+
+    const W: [u32; 8] = [9, 8, 7, 6, 5, 4, 3, 2];
+    let mut sum = 0u32;
+    for i in 0..8 {
+        sum += values[i] * W[i];
+    }
+
+    // 索引为 11 - (sum mod 11)，范围 1..=11；10 → 'A'，11 → '0'。
+    const CHECK: [u8; 12] = *b"0123456789A0";
+    let expected = CHECK[(11 - sum % 11) as usize];
+    if expected != check {
+        return Err(Error::WrongCheckNumber);
+    }
+
+    Ok(RegionalIdNumber {
+        issuer: Issuer::HongKong,
+        serial: trimmed.to_owned(),
+    })
+}
+
+/// 台湾身份证：一位大写字母 + 9 位数字，按官方字母映射表与加权 mod-10 校验。
+pub fn parse_twid(id: &str) -> Result<RegionalIdNumber, Error> {
+    // 字母映射为两位代码，顺序为 A..Z。
+    const LETTER: [u8; 26] = [
+        10, 11, 12, 13, 14, 15, 16, 17, 34, 18, 19, 20, 21, 22, 35, 23, 24, 25, 26, 27, 28, 29, 32,
+        30, 31, 33,
+    ];
+
+    let trimmed = id.trim();
+    let bytes = trimmed.as_bytes();
+    if bytes.len() != 10 {
+        return Err(Error::InvalidLength);
+    }
+    if !bytes[0].is_ascii_uppercase() {
+        return Err(Error::InvalidCharacter);
+    }
+    if !bytes[1..].iter().all(u8::is_ascii_digit) {
+        return Err(Error::InvalidCharacter);
+    }
+
+    let code = LETTER[usize::from(bytes[0] - b'A')];
+    let mut sum = u32::from(code / 10) + u32::from(code % 10) * 9;
+    const W: [u32; 9] = [8, 7, 6, 5, 4, 3, 2, 1, 1];
+    for i in 0..9 {
+        sum += u32::from(bytes[1 + i] - b'0') * W[i];
+    }
+    if sum % 10 != 0 {
+        return Err(Error::WrongCheckNumber);
+    }
+
+    Ok(RegionalIdNumber {
+        issuer: Issuer::Taiwan,
+        serial: trimmed.to_owned(),
+    })
+}
+
+/// 澳门居民身份证：7–8 位数字，且首位为 `1`/`5`/`7`。
+pub fn parse_macau(id: &str) -> Result<RegionalIdNumber, Error> {
+    let trimmed = id.trim();
+    let bytes = trimmed.as_bytes();
+    if !matches!(bytes.len(), 7 | 8) {
+        return Err(Error::InvalidLength);
+    }
+    if !bytes.iter().all(u8::is_ascii_digit) {
+        return Err(Error::InvalidCharacter);
+    }
+    if !matches!(bytes[0], b'1' | b'5' | b'7') {
+        return Err(Error::InvalidCharacter);
+    }
+
+    Ok(RegionalIdNumber {
+        issuer: Issuer::Macau,
+        serial: trimmed.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hkid() {
+        assert_eq!(parse_hkid("A123456(3)").unwrap().issuer, Issuer::HongKong);
+        assert_eq!(parse_hkid("AB987654(3)").unwrap().issuer, Issuer::HongKong);
+        assert_eq!(parse_hkid("A123456(4)").unwrap_err(), Error::WrongCheckNumber);
+        assert_eq!(parse_hkid("A12345(3)").unwrap_err(), Error::InvalidLength);
+    }
+
+    #[test]
+    fn test_twid() {
+        assert_eq!(parse_twid("A123456789").unwrap().issuer, Issuer::Taiwan);
+        assert_eq!(parse_twid("A123456788").unwrap_err(), Error::WrongCheckNumber);
+        assert_eq!(parse_twid("a123456789").unwrap_err(), Error::InvalidCharacter);
+    }
+
+    #[test]
+    fn test_macau() {
+        assert_eq!(parse_macau("1234567").unwrap().issuer, Issuer::Macau);
+        assert_eq!(parse_macau("51234567").unwrap().issuer, Issuer::Macau);
+        assert_eq!(parse_macau("9123456").unwrap_err(), Error::InvalidCharacter);
+    }
+}
@@ -15,6 +15,16 @@ use core::str;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod generate;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub use generate::{generate, FakeOptions};
+
+mod hmt;
+pub use hmt::{parse_hkid, parse_macau, parse_twid, Issuer, RegionalIdNumber};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Error {
@@ -35,10 +45,12 @@ impl core::error::Error for Error {}
 pub struct ParsedIdNumber {
     sex: Sex,
     birthday: (u16, u8, u8),
+    birth_date: time::Date,
     region: Region,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Sex {
     Male,
     Female,
@@ -55,12 +67,81 @@ impl ParsedIdNumber {
         self.birthday
     }
 
+    /// 出生日期，以 [`time::Date`] 形式给出。
+    #[must_use]
+    pub fn birth_date(&self) -> time::Date {
+        self.birth_date
+    }
+
+    /// 截至 `today` 的周岁年龄：当月/日尚未达到生日时减一。
+    #[must_use]
+    pub fn age_at(&self, today: time::Date) -> u16 {
+        let mut age = today.year() - self.birth_date.year();
+        if (today.month(), today.day()) < (self.birth_date.month(), self.birth_date.day()) {
+            age -= 1;
+        }
+        u16::try_from(age).unwrap_or(0)
+    }
+
+    /// 按系统时钟计算的当前周岁年龄。
+    #[cfg(feature = "clock")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
+    #[must_use]
+    pub fn age(&self) -> u16 {
+        self.age_at(time::OffsetDateTime::now_utc().date())
+    }
+
     #[must_use]
     pub fn region(&self) -> &Region {
         &self.region
     }
 }
 
+/// 以稳定的 JSON 结构输出，生日格式化为 ISO 日期。
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for ParsedIdNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let (year, month, day) = self.birthday;
+        let birthday = format!("{year:04}-{month:02}-{day:02}");
+
+        let mut state = serializer.serialize_struct("ParsedIdNumber", 3)?;
+        state.serialize_field("sex", &self.sex)?;
+        state.serialize_field("birthday", &birthday)?;
+        state.serialize_field("region", &self.region)?;
+        state.end()
+    }
+}
+
+/// 自动识别一代 / 二代身份证号并解析。
+///
+/// 去除首尾 ASCII 空白后按长度分派：15 位走 [`parse_v1`]，18 位走 [`parse_v2`]，
+/// 其余长度返回 [`Error::InvalidLength`]。末位的小写 `x` 会被归一化为 `'X'`，
+/// 以兼容网页表单常见的大小写输入。
+pub fn parse(id: &str) -> Result<ParsedIdNumber, Error> {
+    let id = id.trim_matches(|c: char| c.is_ascii_whitespace());
+
+    match id.len() {
+        15 => parse_v1(id),
+        18 => {
+            if let Some(prefix) = id.strip_suffix('x') {
+                let mut buf = String::with_capacity(18);
+                buf.push_str(prefix);
+                buf.push('X');
+                parse_v2(&buf)
+            } else {
+                parse_v2(id)
+            }
+        }
+        _ => Err(Error::InvalidLength),
+    }
+}
+
 /// 二代身份证号 (18位)
 pub fn parse_v2(id_str: &str) -> Result<ParsedIdNumber, Error> {
     let id: [u8; 18] = id_str
@@ -78,22 +159,13 @@ pub fn parse_v2(id_str: &str) -> Result<ParsedIdNumber, Error> {
     }
 
     {
-        const W: [u8; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
-        let mut sum: u32 = if id[17] == b'X' {
-            10
-        } else {
-            u32::from(id[17] - b'0')
-        };
-        for i in 0..17 {
-            sum += u32::from(id[i] - b'0') * u32::from(W[i]);
-            sum %= 11;
-        }
-        if sum != 1 {
+        let body: &[u8; 17] = id[..17].try_into().unwrap();
+        if id[17] != check_digit(body) {
             return Err(Error::WrongCheckNumber);
         }
     }
 
-    let birthday = {
+    let (birthday, birth_date) = {
         let year = u16_from_char4([id[6], id[7], id[8], id[9]]);
         let month = u8_from_char2([id[10], id[11]]);
         let day = u8_from_char2([id[12], id[13]]);
@@ -103,11 +175,11 @@ pub fn parse_v2(id_str: &str) -> Result<ParsedIdNumber, Error> {
             return Err(Error::InvalidBirthday);
         }
 
-        if !validate_ymd(year, month, day) {
+        let Some(date) = validate_ymd(year, month, day) else {
             return Err(Error::InvalidBirthday);
-        }
+        };
 
-        (year, month, day)
+        ((year, month, day), date)
     };
 
     let region = {
@@ -124,6 +196,7 @@ pub fn parse_v2(id_str: &str) -> Result<ParsedIdNumber, Error> {
     Ok(ParsedIdNumber {
         sex,
         birthday,
+        birth_date,
         region,
     })
 }
@@ -141,16 +214,16 @@ pub fn parse_v1(id_str: &str) -> Result<ParsedIdNumber, Error> {
         }
     }
 
-    let birthday = {
+    let (birthday, birth_date) = {
         let year = u16_from_char4([b'1', b'9', id[6], id[7]]);
         let month = u8_from_char2([id[8], id[9]]);
         let day = u8_from_char2([id[10], id[11]]);
 
-        if !validate_ymd(year, month, day) {
+        let Some(date) = validate_ymd(year, month, day) else {
             return Err(Error::InvalidBirthday);
-        }
+        };
 
-        (year, month, day)
+        ((year, month, day), date)
     };
 
     let region = {
@@ -167,21 +240,108 @@ pub fn parse_v1(id_str: &str) -> Result<ParsedIdNumber, Error> {
     Ok(ParsedIdNumber {
         sex,
         birthday,
+        birth_date,
         region,
     })
 }
 
+/// 按 GB 11643-1999 计算 18 位号码的校验码。
+///
+/// 入参为 17 位本体（必须全部是 ASCII 数字），返回校验位的 ASCII 字节，
+/// 余数为 2 时返回 `b'X'`。
+#[must_use]
+pub fn check_digit(body: &[u8; 17]) -> u8 {
+    const W: [u8; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+    let mut s: u32 = 0;
+    for i in 0..17 {
+        s += u32::from(body[i] - b'0') * u32::from(W[i]);
+    }
+    s %= 11;
+    b"10X98765432"[s as usize]
+}
+
+/// 仅校验二代身份证号 (18位) 的格式与校验位，不构造 [`ParsedIdNumber`]。
+#[must_use]
+pub fn validate_v2(id: &str) -> bool {
+    let id: &[u8; 18] = match id.as_bytes().try_into() {
+        Ok(id) => id,
+        Err(_) => return false,
+    };
+
+    for i in 0..17 {
+        if !id[i].is_ascii_digit() {
+            return false;
+        }
+    }
+    if !id[17].is_ascii_digit() && id[17] != b'X' {
+        return false;
+    }
+
+    let body: &[u8; 17] = id[..17].try_into().unwrap();
+    id[17] == check_digit(body)
+}
+
+/// 仅校验一代身份证号 (15位) 的格式，不构造 [`ParsedIdNumber`]。
+#[must_use]
+pub fn validate_v1(id: &str) -> bool {
+    let id: &[u8; 15] = match id.as_bytes().try_into() {
+        Ok(id) => id,
+        Err(_) => return false,
+    };
+
+    id.iter().all(u8::is_ascii_digit)
+}
+
+/// 将一代身份证号 (15位) 升级为二代身份证号 (18位)
+///
+/// 在原始的 `region(6) + YYMMDD(6) + seq(3)` 前补入世纪 `"19"` 得到 17 位本体，
+/// 再按 GB 11643-1999 计算校验码并追加。格式非法的输入会与 [`parse_v1`] 一样报错。
+pub fn upgrade_v1_to_v2(id_str: &str) -> Result<String, Error> {
+    let id: [u8; 15] = id_str
+        .as_bytes()
+        .try_into()
+        .map_err(|_| Error::InvalidLength)?;
+
+    for i in 0..15 {
+        if !id[i].is_ascii_digit() {
+            return Err(Error::InvalidCharacter);
+        }
+    }
+
+    {
+        let year = u16_from_char4([b'1', b'9', id[6], id[7]]);
+        let month = u8_from_char2([id[8], id[9]]);
+        let day = u8_from_char2([id[10], id[11]]);
+
+        if validate_ymd(year, month, day).is_none() {
+            return Err(Error::InvalidBirthday);
+        }
+    }
+
+    let mut body = [0u8; 17];
+    body[..6].copy_from_slice(&id[..6]);
+    body[6] = b'1';
+    body[7] = b'9';
+    body[8..].copy_from_slice(&id[6..]);
+
+    let mut id18 = [0u8; 18];
+    id18[..17].copy_from_slice(&body);
+    id18[17] = check_digit(&body);
+    Ok(str::from_utf8(&id18).unwrap().to_owned())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Region {
     pub province: Option<&'static str>,
     pub city: Option<&'static str>,
     pub district: Option<&'static str>,
 }
 
-fn get_region(region_code: [u8; 6], year: u16) -> Region {
-    static DATASET: LazyLock<HashMap<u16, HashMap<&'static str, &'static str>>> =
-        LazyLock::new(|| serde_json::from_str(include_str!("region.json")).unwrap());
+static DATASET: LazyLock<HashMap<u16, HashMap<&'static str, &'static str>>> =
+    LazyLock::new(|| serde_json::from_str(include_str!("region.json")).unwrap());
 
+fn get_region(region_code: [u8; 6], year: u16) -> Region {
     let c = &region_code;
     let t1 = [c[0], c[1], b'0', b'0', b'0', b'0'];
     let t1 = str::from_utf8(&t1).unwrap();
@@ -218,13 +378,9 @@ fn get_region(region_code: [u8; 6], year: u16) -> Region {
     }
 }
 
-fn validate_ymd(year: u16, month: u8, day: u8) -> bool {
-    let month: time::Month = match month.try_into() {
-        Ok(m) => m,
-        Err(_) => return false,
-    };
-
-    time::Date::from_calendar_date(i32::from(year), month, day).is_ok()
+fn validate_ymd(year: u16, month: u8, day: u8) -> Option<time::Date> {
+    let month: time::Month = month.try_into().ok()?;
+    time::Date::from_calendar_date(i32::from(year), month, day).ok()
 }
 
 #[inline(always)]
@@ -270,4 +426,49 @@ mod tests {
             assert_eq!(parsed.region().district, Some("洪山区"));
         }
     }
+
+    #[test]
+    fn test_upgrade_v1_to_v2() {
+        assert_eq!(
+            upgrade_v1_to_v2("420111820325102").unwrap(),
+            "420111198203251029"
+        );
+        assert_eq!(upgrade_v1_to_v2("42011182032510").unwrap_err(), Error::InvalidLength);
+        assert_eq!(
+            upgrade_v1_to_v2("420111821325102").unwrap_err(),
+            Error::InvalidBirthday
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate_v2("11010519491231002X"));
+        assert!(validate_v2("420111198203251029"));
+        assert!(!validate_v2("420111198203251020"));
+        assert!(!validate_v2("42011119820325102"));
+
+        assert!(validate_v1("420111820325102"));
+        assert!(!validate_v1("42011182032510x"));
+    }
+
+    #[test]
+    fn test_age_at() {
+        let date = |y, m: u8, d| {
+            time::Date::from_calendar_date(y, m.try_into().unwrap(), d).unwrap()
+        };
+
+        let parsed = parse_v2("420111198203251029").unwrap();
+        assert_eq!(parsed.birth_date(), date(1982, 3, 25));
+
+        assert_eq!(parsed.age_at(date(2020, 3, 24)), 37);
+        assert_eq!(parsed.age_at(date(2020, 3, 25)), 38);
+    }
+
+    #[test]
+    fn test_parse_autodetect() {
+        assert_eq!(parse("  420111198203251029 ").unwrap().birthday, (1982, 3, 25));
+        assert_eq!(parse("11010519491231002x").unwrap().birthday, (1949, 12, 31));
+        assert_eq!(parse("420111820325102").unwrap().birthday, (1982, 3, 25));
+        assert_eq!(parse("12345").unwrap_err(), Error::InvalidLength);
+    }
 }